@@ -0,0 +1,73 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+
+use crate::RespFrame;
+
+/// Cloneable handle onto the key-value store. Cloning is cheap (an `Arc` bump) so every
+/// connection can hold its own `Backend` that all point at the same underlying maps.
+#[derive(Clone, Debug)]
+pub struct Backend(Arc<BackendInner>);
+
+#[derive(Debug)]
+pub struct BackendInner {
+    pub(crate) map: DashMap<String, RespFrame>,
+    pub(crate) hmap: DashMap<String, DashMap<String, RespFrame>>,
+}
+
+impl Deref for Backend {
+    type Target = BackendInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Self(Arc::new(BackendInner::default()))
+    }
+}
+
+impl Default for BackendInner {
+    fn default() -> Self {
+        Self {
+            map: DashMap::new(),
+            hmap: DashMap::new(),
+        }
+    }
+}
+
+impl Backend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<RespFrame> {
+        self.map.get(key).map(|v| v.value().clone())
+    }
+
+    pub fn set(&self, key: String, value: RespFrame) {
+        self.map.insert(key, value);
+    }
+
+    pub fn hget(&self, key: &str, field: &str) -> Option<RespFrame> {
+        self.hmap
+            .get(key)
+            .and_then(|hmap| hmap.get(field).map(|v| v.value().clone()))
+    }
+
+    pub fn hset(&self, key: String, field: String, value: RespFrame) {
+        let hmap = self.hmap.entry(key).or_default();
+        hmap.insert(field, value);
+    }
+
+    pub fn hgetall(&self, key: &str) -> Option<Vec<(String, RespFrame)>> {
+        self.hmap.get(key).map(|hmap| {
+            hmap.iter()
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use simple_redis::{stream_handler, Backend};
+use tokio::net::TcpListener;
+use tracing::{info, warn};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let addr = "0.0.0.0:6379";
+    let listener = TcpListener::bind(addr).await?;
+    info!("simple-redis-server listening on {}", addr);
+
+    let backend = Backend::new();
+
+    loop {
+        let (stream, raddr) = listener.accept().await?;
+        info!("Accepted connection from {}", raddr);
+        let cloned_backend = backend.clone();
+        tokio::spawn(async move {
+            if let Err(e) = stream_handler(stream, cloned_backend).await {
+                warn!("Error handling connection from {}: {:?}", raddr, e);
+            }
+        });
+    }
+}
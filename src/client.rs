@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use futures::{SinkExt, StreamExt};
+use tokio::net::{TcpStream, ToSocketAddrs};
+use tokio_util::codec::Framed;
+
+use crate::{
+    cmd::{Get, HGet, Set},
+    RespArray, RespCodec, RespError, RespFrame,
+};
+
+/// Symmetric counterpart to the server's command dispatch: encode a command struct into
+/// a `RespArray` request, send it over the wire, and decode the reply back into a `RespFrame`.
+#[async_trait]
+pub trait AsyncClient {
+    async fn send(&mut self, cmd: RespFrame) -> Result<RespFrame, RespError>;
+
+    async fn get(&mut self, key: &str) -> Result<RespFrame, RespError> {
+        let cmd = Get {
+            key: key.to_string(),
+        };
+        self.send(RespArray::from(cmd).into()).await
+    }
+
+    async fn set(&mut self, key: &str, value: RespFrame) -> Result<RespFrame, RespError> {
+        let cmd = Set {
+            key: key.to_string(),
+            value,
+        };
+        self.send(RespArray::from(cmd).into()).await
+    }
+
+    async fn hget(&mut self, key: &str, field: &str) -> Result<RespFrame, RespError> {
+        let cmd = HGet {
+            key: key.to_string(),
+            field: field.to_string(),
+        };
+        self.send(RespArray::from(cmd).into()).await
+    }
+}
+
+/// `AsyncClient` over a `Framed<TcpStream, RespCodec>`, mirroring how the server drives
+/// its side of the same codec.
+pub struct TcpAsyncClient {
+    framed: Framed<TcpStream, RespCodec>,
+}
+
+impl TcpAsyncClient {
+    pub async fn connect(addr: impl ToSocketAddrs) -> Result<Self, RespError> {
+        let stream = TcpStream::connect(addr).await?;
+        Ok(Self {
+            framed: Framed::new(stream, RespCodec::default()),
+        })
+    }
+}
+
+#[async_trait]
+impl AsyncClient for TcpAsyncClient {
+    async fn send(&mut self, cmd: RespFrame) -> Result<RespFrame, RespError> {
+        self.framed.send(cmd).await?;
+        self.framed
+            .next()
+            .await
+            .ok_or_else(|| RespError::IoError("connection closed by peer".to_string()))?
+    }
+}
+
+/// Blocking wrapper around `TcpAsyncClient` for callers that don't want to run their own
+/// Tokio runtime. Reconnects once and retries if a send fails with a broken pipe or a
+/// connection reset; a connection the peer closed cleanly is reported as an error, not retried.
+pub struct SyncClient {
+    addr: String,
+    runtime: tokio::runtime::Runtime,
+    client: TcpAsyncClient,
+}
+
+impl SyncClient {
+    pub fn connect(addr: impl Into<String>) -> Result<Self, RespError> {
+        let addr = addr.into();
+        let runtime = tokio::runtime::Runtime::new()?;
+        let client = runtime.block_on(TcpAsyncClient::connect(addr.clone()))?;
+        Ok(Self {
+            addr,
+            runtime,
+            client,
+        })
+    }
+
+    pub fn send(&mut self, cmd: RespFrame) -> Result<RespFrame, RespError> {
+        match self.runtime.block_on(self.client.send(cmd.clone())) {
+            Err(RespError::IoError(ref msg)) if is_broken_pipe(msg) => {
+                self.reconnect()?;
+                self.runtime.block_on(self.client.send(cmd))
+            }
+            result => result,
+        }
+    }
+
+    pub fn get(&mut self, key: &str) -> Result<RespFrame, RespError> {
+        let cmd = Get {
+            key: key.to_string(),
+        };
+        self.send(RespArray::from(cmd).into())
+    }
+
+    pub fn set(&mut self, key: &str, value: RespFrame) -> Result<RespFrame, RespError> {
+        let cmd = Set {
+            key: key.to_string(),
+            value,
+        };
+        self.send(RespArray::from(cmd).into())
+    }
+
+    pub fn hget(&mut self, key: &str, field: &str) -> Result<RespFrame, RespError> {
+        let cmd = HGet {
+            key: key.to_string(),
+            field: field.to_string(),
+        };
+        self.send(RespArray::from(cmd).into())
+    }
+
+    fn reconnect(&mut self) -> Result<(), RespError> {
+        self.client = self
+            .runtime
+            .block_on(TcpAsyncClient::connect(self.addr.clone()))?;
+        Ok(())
+    }
+}
+
+fn is_broken_pipe(msg: &str) -> bool {
+    msg.contains("Broken pipe") || msg.contains("Connection reset")
+}
@@ -0,0 +1,104 @@
+use crate::{
+    cmd::{extract_args, CommandError, CommandExecutor, Hello},
+    Backend, RespArray, RespFrame, RespMap,
+};
+
+impl TryFrom<RespArray> for Hello {
+    type Error = CommandError;
+
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        match value.first() {
+            Some(RespFrame::BulkString(cmd)) if cmd.as_ref().eq_ignore_ascii_case(b"hello") => {}
+            _ => {
+                return Err(CommandError::InvalidCommand(
+                    "HELLO command must start with HELLO".to_string(),
+                ))
+            }
+        }
+
+        if value.len() > 2 {
+            return Err(CommandError::InvalidArgument(
+                "HELLO takes at most one argument: [protover]".to_string(),
+            ));
+        }
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        let proto = match args.next() {
+            Some(RespFrame::BulkString(protover)) => {
+                String::from_utf8(protover.0)?
+                    .parse::<i64>()
+                    .map_err(|_| CommandError::InvalidArgument("Invalid protover".to_string()))?
+            }
+            Some(_) => {
+                return Err(CommandError::InvalidArgument(
+                    "Invalid protover".to_string(),
+                ))
+            }
+            None => 2,
+        };
+
+        if proto != 2 && proto != 3 {
+            return Err(CommandError::InvalidArgument(
+                "NOPROTO unsupported protocol version".to_string(),
+            ));
+        }
+
+        Ok(Hello { proto })
+    }
+}
+
+impl CommandExecutor for Hello {
+    fn execute(self, _backend: &Backend) -> RespFrame {
+        let mut map = RespMap::new();
+        map.insert("server".to_string(), "redis".into());
+        map.insert("version".to_string(), "0.1.0".into());
+        map.insert("proto".to_string(), self.proto.into());
+        map.insert("mode".to_string(), "standalone".into());
+        map.insert("role".to_string(), "master".into());
+        map.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use bytes::BytesMut;
+
+    use crate::{cmd::Hello, RespDecode};
+
+    use super::*;
+
+    #[test]
+    fn test_hello_default_proto() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*1\r\n$5\r\nhello\r\n");
+        let cmd = RespArray::decode(&mut buf)?;
+        let hello = Hello::try_from(cmd)?;
+        assert_eq!(hello.proto, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hello_proto3() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*2\r\n$5\r\nhello\r\n$1\r\n3\r\n");
+        let cmd = RespArray::decode(&mut buf)?;
+        let hello = Hello::try_from(cmd)?;
+        assert_eq!(hello.proto, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hello_execute() -> Result<()> {
+        let backend = Backend::new();
+        let hello = Hello { proto: 3 };
+        let result = hello.execute(&backend);
+        match result {
+            RespFrame::Map(map) => {
+                assert_eq!(map.get("proto"), Some(&RespFrame::Integer(3)));
+            }
+            _ => panic!("expected a RespMap"),
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,38 @@
 use crate::{
-    cmd::{extract_args, validate_command, CommandError, HGet, HGetAll, HSet},
-    RespArray, RespFrame,
+    cmd::{extract_args, validate_command, CommandError, CommandExecutor, HGet, HGetAll, HSet},
+    Backend, BulkString, RespArray, RespFrame, RespMap, RespNull, SimpleString,
 };
 
+impl From<HGet> for RespArray {
+    fn from(value: HGet) -> Self {
+        RespArray::new(vec![
+            BulkString::from("hget").into(),
+            BulkString::from(value.key.as_str()).into(),
+            BulkString::from(value.field.as_str()).into(),
+        ])
+    }
+}
+
+impl From<HSet> for RespArray {
+    fn from(value: HSet) -> Self {
+        RespArray::new(vec![
+            BulkString::from("hset").into(),
+            BulkString::from(value.key.as_str()).into(),
+            BulkString::from(value.field.as_str()).into(),
+            value.value,
+        ])
+    }
+}
+
+impl From<HGetAll> for RespArray {
+    fn from(value: HGetAll) -> Self {
+        RespArray::new(vec![
+            BulkString::from("hgetall").into(),
+            BulkString::from(value.key.as_str()).into(),
+        ])
+    }
+}
+
 impl TryFrom<RespArray> for HGet {
     type Error = CommandError;
 
@@ -60,6 +90,34 @@ impl TryFrom<RespArray> for HGetAll {
     }
 }
 
+impl CommandExecutor for HGet {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        match backend.hget(&self.key, &self.field) {
+            Some(value) => value,
+            None => RespNull.into(),
+        }
+    }
+}
+
+impl CommandExecutor for HSet {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        backend.hset(self.key, self.field, self.value);
+        SimpleString::new("OK").into()
+    }
+}
+
+impl CommandExecutor for HGetAll {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        let mut map = RespMap::new();
+        if let Some(pairs) = backend.hgetall(&self.key) {
+            for (field, value) in pairs {
+                map.insert(field, value);
+            }
+        }
+        map.into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
@@ -101,4 +159,41 @@ mod tests {
         assert_eq!(hgetall.key, "key");
         Ok(())
     }
+
+    #[test]
+    fn test_hset_hget_execute() {
+        let backend = Backend::new();
+        let cmd = HSet {
+            key: "key".to_string(),
+            field: "field".to_string(),
+            value: RespFrame::BulkString(BulkString::from("value")),
+        };
+        let result = cmd.execute(&backend);
+        assert_eq!(result, RespFrame::SimpleString("OK".into()));
+
+        let cmd = HGet {
+            key: "key".to_string(),
+            field: "field".to_string(),
+        };
+        let result = cmd.execute(&backend);
+        assert_eq!(result, RespFrame::BulkString(BulkString::from("value")));
+    }
+
+    #[test]
+    fn test_hgetall_execute() {
+        let backend = Backend::new();
+        backend.hset(
+            "key".to_string(),
+            "field".to_string(),
+            RespFrame::BulkString(BulkString::from("value")),
+        );
+
+        let cmd = HGetAll {
+            key: "key".to_string(),
+        };
+        let result = cmd.execute(&backend);
+        let mut map = RespMap::new();
+        map.insert("field".to_string(), RespFrame::BulkString(BulkString::from("value")));
+        assert_eq!(result, RespFrame::Map(map));
+    }
 }
@@ -1,8 +1,27 @@
 use crate::{
-    cmd::{extract_args, validate_command, CommandError, Get, Set},
-    RespArray, RespFrame,
+    cmd::{extract_args, validate_command, CommandError, CommandExecutor, Get, Set},
+    Backend, BulkString, RespArray, RespFrame, RespNull, SimpleString,
 };
 
+impl From<Get> for RespArray {
+    fn from(value: Get) -> Self {
+        RespArray::new(vec![
+            BulkString::from("get").into(),
+            BulkString::from(value.key.as_str()).into(),
+        ])
+    }
+}
+
+impl From<Set> for RespArray {
+    fn from(value: Set) -> Self {
+        RespArray::new(vec![
+            BulkString::from("set").into(),
+            BulkString::from(value.key.as_str()).into(),
+            value.value,
+        ])
+    }
+}
+
 impl TryFrom<RespArray> for Get {
     type Error = CommandError;
 
@@ -37,6 +56,22 @@ impl TryFrom<RespArray> for Set {
     }
 }
 
+impl CommandExecutor for Get {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        match backend.get(&self.key) {
+            Some(value) => value,
+            None => RespNull.into(),
+        }
+    }
+}
+
+impl CommandExecutor for Set {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        backend.set(self.key, self.value);
+        SimpleString::new("OK").into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
@@ -66,4 +101,32 @@ mod tests {
         assert_eq!(set.value, RespFrame::BulkString(BulkString::from("value")));
         Ok(())
     }
+
+    #[test]
+    fn test_set_get_execute() -> Result<()> {
+        let backend = Backend::new();
+        let cmd = Set {
+            key: "key".to_string(),
+            value: RespFrame::BulkString(BulkString::from("value")),
+        };
+        let result = cmd.execute(&backend);
+        assert_eq!(result, RespFrame::SimpleString("OK".into()));
+
+        let cmd = Get {
+            key: "key".to_string(),
+        };
+        let result = cmd.execute(&backend);
+        assert_eq!(result, RespFrame::BulkString(BulkString::from("value")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_missing_key_execute() {
+        let backend = Backend::new();
+        let cmd = Get {
+            key: "missing".to_string(),
+        };
+        let result = cmd.execute(&backend);
+        assert_eq!(result, RespFrame::Null(RespNull));
+    }
 }
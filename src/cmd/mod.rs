@@ -0,0 +1,139 @@
+mod hello;
+mod hmap;
+mod map;
+
+use enum_dispatch::enum_dispatch;
+use thiserror::Error;
+
+use crate::{Backend, RespArray, RespFrame, SimpleError};
+
+#[derive(Error, Debug)]
+pub enum CommandError {
+    #[error("Invalid command: {0}")]
+    InvalidCommand(String),
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("{0}")]
+    Utf8Error(#[from] std::string::FromUtf8Error),
+}
+
+#[enum_dispatch]
+pub trait CommandExecutor {
+    fn execute(self, backend: &Backend) -> RespFrame;
+}
+
+#[enum_dispatch(CommandExecutor)]
+#[derive(Debug)]
+pub enum Command {
+    Get(Get),
+    Set(Set),
+    HGet(HGet),
+    HSet(HSet),
+    HGetAll(HGetAll),
+    Hello(Hello),
+    Unrecognized(Unrecognized),
+}
+
+#[derive(Debug)]
+pub struct Unrecognized;
+
+impl CommandExecutor for Unrecognized {
+    fn execute(self, _backend: &Backend) -> RespFrame {
+        SimpleError::new("ERR unknown command").into()
+    }
+}
+
+impl TryFrom<RespArray> for Command {
+    type Error = CommandError;
+
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        match value.first() {
+            Some(RespFrame::BulkString(cmd)) => match cmd.as_ref().to_ascii_lowercase().as_slice()
+            {
+                b"get" => Ok(Get::try_from(value)?.into()),
+                b"set" => Ok(Set::try_from(value)?.into()),
+                b"hget" => Ok(HGet::try_from(value)?.into()),
+                b"hset" => Ok(HSet::try_from(value)?.into()),
+                b"hgetall" => Ok(HGetAll::try_from(value)?.into()),
+                b"hello" => Ok(Hello::try_from(value)?.into()),
+                _ => Ok(Unrecognized.into()),
+            },
+            _ => Err(CommandError::InvalidCommand(
+                "Command must have a BulkString as the first argument".to_string(),
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Get {
+    pub(crate) key: String,
+}
+
+#[derive(Debug)]
+pub struct Set {
+    pub(crate) key: String,
+    pub(crate) value: RespFrame,
+}
+
+#[derive(Debug)]
+pub struct HGet {
+    pub(crate) key: String,
+    pub(crate) field: String,
+}
+
+#[derive(Debug)]
+pub struct HSet {
+    pub(crate) key: String,
+    pub(crate) field: String,
+    pub(crate) value: RespFrame,
+}
+
+#[derive(Debug)]
+pub struct HGetAll {
+    pub(crate) key: String,
+}
+
+#[derive(Debug)]
+pub struct Hello {
+    pub(crate) proto: i64,
+}
+
+fn validate_command(
+    value: &RespArray,
+    names: &[&'static str],
+    n_args: usize,
+) -> Result<(), CommandError> {
+    if value.len() != n_args + names.len() {
+        return Err(CommandError::InvalidArgument(format!(
+            "{} command must have exactly {} argument(s)",
+            names.join(" "),
+            n_args
+        )));
+    }
+
+    for (i, name) in names.iter().enumerate() {
+        match value[i] {
+            RespFrame::BulkString(ref cmd) => {
+                if cmd.as_ref().to_ascii_lowercase() != name.as_bytes() {
+                    return Err(CommandError::InvalidCommand(format!(
+                        "Invalid command: expected {}, got {}",
+                        name,
+                        String::from_utf8_lossy(cmd.as_ref())
+                    )));
+                }
+            }
+            _ => {
+                return Err(CommandError::InvalidCommand(
+                    "Command must have a BulkString as the first argument".to_string(),
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn extract_args(value: RespArray, start: usize) -> Result<Vec<RespFrame>, CommandError> {
+    Ok(value.0.into_iter().skip(start).collect::<Vec<RespFrame>>())
+}
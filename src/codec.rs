@@ -0,0 +1,38 @@
+use bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{RespError, RespFrame, RespVersion};
+
+/// Tokio codec that drives a `RespFrame` stream directly from a `Framed<TcpStream, RespCodec>`,
+/// so callers never have to manage `BytesMut` buffers by hand.
+///
+/// Carries the connection's negotiated `RespVersion` so a `HELLO`-upgraded connection
+/// encodes every subsequent frame (null, boolean, map, ...) in its requested dialect.
+#[derive(Debug, Default)]
+pub struct RespCodec {
+    version: RespVersion,
+}
+
+impl RespCodec {
+    pub fn set_version(&mut self, version: RespVersion) {
+        self.version = version;
+    }
+}
+
+impl Decoder for RespCodec {
+    type Item = RespFrame;
+    type Error = RespError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        RespFrame::decode(src)
+    }
+}
+
+impl Encoder<RespFrame> for RespCodec {
+    type Error = RespError;
+
+    fn encode(&mut self, item: RespFrame, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        dst.extend_from_slice(&item.encode_with(self.version));
+        Ok(())
+    }
+}
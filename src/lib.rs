@@ -0,0 +1,13 @@
+mod backend;
+mod client;
+mod cmd;
+mod codec;
+mod network;
+mod resp;
+
+pub use backend::*;
+pub use client::*;
+pub use cmd::*;
+pub use codec::*;
+pub use network::*;
+pub use resp::*;
@@ -0,0 +1,56 @@
+use anyhow::Result;
+use futures::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio_util::codec::Framed;
+use tracing::info;
+
+use crate::{Backend, Command, CommandExecutor, RespCodec, RespFrame, RespVersion, SimpleError};
+
+/// Drives a single client connection: decode a `RespFrame`, dispatch it through
+/// `CommandExecutor`, and write the resulting frame back. A bad command gets a
+/// `SimpleError` reply and the loop goes on; a frame that fails to decode ends this
+/// connection's loop but never takes down the server.
+///
+/// `HELLO` is handled specially: it negotiates the connection's `RespVersion`, which is
+/// applied to the codec before its own reply (and everything after) is encoded.
+pub async fn stream_handler(stream: TcpStream, backend: Backend) -> Result<()> {
+    let mut framed = Framed::new(stream, RespCodec::default());
+
+    while let Some(result) = framed.next().await {
+        let frame = match result {
+            Ok(frame) => frame,
+            Err(e) => {
+                info!("Error decoding frame: {:?}", e);
+                break;
+            }
+        };
+
+        let (response, version) = request_handler(frame, &backend);
+        if let Some(version) = version {
+            framed.codec_mut().set_version(version);
+        }
+        framed.send(response).await?;
+    }
+
+    Ok(())
+}
+
+fn request_handler(frame: RespFrame, backend: &Backend) -> (RespFrame, Option<RespVersion>) {
+    let command = match frame {
+        RespFrame::Array(array) => Command::try_from(array),
+        _ => return (SimpleError::new("ERR expected array request").into(), None),
+    };
+
+    match command {
+        Ok(Command::Hello(hello)) => {
+            let version = if hello.proto >= 3 {
+                RespVersion::Resp3
+            } else {
+                RespVersion::Resp2
+            };
+            (hello.execute(backend), Some(version))
+        }
+        Ok(cmd) => (cmd.execute(backend), None),
+        Err(e) => (SimpleError::new(e.to_string()).into(), None),
+    }
+}
@@ -0,0 +1,768 @@
+use bytes::{Buf, BytesMut};
+
+use crate::{
+    BigNumber, BulkError, BulkString, RespArray, RespDecode, RespError, RespFrame, RespMap,
+    RespNull, RespNullArray, RespNullBulkString, RespPush, RespSet, SimpleError, SimpleString,
+    VerbatimString,
+};
+
+const CRLF: &[u8] = b"\r\n";
+const CRLF_LEN: usize = CRLF.len();
+
+impl RespFrame {
+    pub fn decode(buf: &mut BytesMut) -> Result<Option<Self>, RespError> {
+        if buf.is_empty() {
+            return Ok(None);
+        }
+
+        let result = match buf[0] {
+            b'+' => SimpleString::decode(buf).map(RespFrame::from),
+            b'-' => SimpleError::decode(buf).map(RespFrame::from),
+            b':' => i64::decode(buf).map(RespFrame::from),
+            b'$' => match RespNullBulkString::decode(buf) {
+                Ok(frame) => Ok(RespFrame::from(frame)),
+                Err(RespError::NotComplete) => Err(RespError::NotComplete),
+                Err(_) => BulkString::decode(buf).map(RespFrame::from),
+            },
+            b'*' => match RespNullArray::decode(buf) {
+                Ok(frame) => Ok(RespFrame::from(frame)),
+                Err(RespError::NotComplete) => Err(RespError::NotComplete),
+                Err(_) => RespArray::decode(buf).map(RespFrame::from),
+            },
+            b'_' => RespNull::decode(buf).map(RespFrame::from),
+            b'#' => bool::decode(buf).map(RespFrame::from),
+            b',' => f64::decode(buf).map(RespFrame::from),
+            b'%' => RespMap::decode(buf).map(RespFrame::from),
+            b'~' => RespSet::decode(buf).map(RespFrame::from),
+            b'(' => BigNumber::decode(buf).map(RespFrame::from),
+            b'!' => BulkError::decode(buf).map(RespFrame::from),
+            b'=' => VerbatimString::decode(buf).map(RespFrame::from),
+            b'>' => RespPush::decode(buf).map(RespFrame::from),
+            _ => Err(RespError::InvalidFrameType(format!(
+                "Unknown frame type: {:?}",
+                buf
+            ))),
+        };
+
+        match result {
+            Ok(frame) => Ok(Some(frame)),
+            Err(RespError::NotComplete) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl RespDecode for SimpleString {
+    const PREFIX: &'static str = "+";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = String::from_utf8_lossy(&data[Self::PREFIX.len()..end]).to_string();
+        Ok(SimpleString::new(s))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = find_crlf(buf, 1).ok_or(RespError::NotComplete)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for SimpleError {
+    const PREFIX: &'static str = "-";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = String::from_utf8_lossy(&data[Self::PREFIX.len()..end]).to_string();
+        Ok(SimpleError::new(s))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = find_crlf(buf, 1).ok_or(RespError::NotComplete)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for i64 {
+    const PREFIX: &'static str = ":";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = std::str::from_utf8(&data[Self::PREFIX.len()..end])?;
+        Ok(s.parse()?)
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = find_crlf(buf, 1).ok_or(RespError::NotComplete)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for BulkString {
+    const PREFIX: &'static str = "$";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let remained = &buf[end + CRLF_LEN..];
+        if remained.len() < len + CRLF_LEN {
+            return Err(RespError::NotComplete);
+        }
+
+        buf.advance(end + CRLF_LEN);
+        let data = buf.split_to(len + CRLF_LEN);
+        Ok(BulkString::new(data[..len].to_vec()))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN + len + CRLF_LEN)
+    }
+}
+
+impl RespDecode for RespNullBulkString {
+    const PREFIX: &'static str = "$";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        extract_fixed_data(buf, "$-1\r\n", "NullBulkString")?;
+        Ok(RespNullBulkString)
+    }
+
+    fn expect_length(_buf: &[u8]) -> Result<usize, RespError> {
+        Ok(5)
+    }
+}
+
+impl RespDecode for RespArray {
+    const PREFIX: &'static str = "*";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let total_len = calc_total_length(buf, end, len, Self::PREFIX)?;
+        if buf.len() < total_len {
+            return Err(RespError::NotComplete);
+        }
+
+        buf.advance(end + CRLF_LEN);
+
+        let mut frames = Vec::with_capacity(len);
+        for _ in 0..len {
+            frames.push(
+                RespFrame::decode(buf)?.ok_or(RespError::NotComplete)?,
+            );
+        }
+
+        Ok(RespArray::new(frames))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        calc_total_length(buf, end, len, Self::PREFIX)
+    }
+}
+
+impl RespDecode for RespNullArray {
+    const PREFIX: &'static str = "*";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        extract_fixed_data(buf, "*-1\r\n", "NullArray")?;
+        Ok(RespNullArray)
+    }
+
+    fn expect_length(_buf: &[u8]) -> Result<usize, RespError> {
+        Ok(5)
+    }
+}
+
+impl RespDecode for RespNull {
+    const PREFIX: &'static str = "_";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        extract_fixed_data(buf, "_\r\n", "Null")?;
+        Ok(RespNull)
+    }
+
+    fn expect_length(_buf: &[u8]) -> Result<usize, RespError> {
+        Ok(3)
+    }
+}
+
+impl RespDecode for bool {
+    const PREFIX: &'static str = "#";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        match extract_fixed_data(buf, "#t\r\n", "Boolean") {
+            Ok(_) => Ok(true),
+            Err(RespError::NotComplete) => Err(RespError::NotComplete),
+            Err(_) => {
+                extract_fixed_data(buf, "#f\r\n", "Boolean")?;
+                Ok(false)
+            }
+        }
+    }
+
+    fn expect_length(_buf: &[u8]) -> Result<usize, RespError> {
+        Ok(4)
+    }
+}
+
+impl RespDecode for f64 {
+    const PREFIX: &'static str = ",";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = std::str::from_utf8(&data[Self::PREFIX.len()..end])?;
+        Ok(s.parse()?)
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = find_crlf(buf, 1).ok_or(RespError::NotComplete)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for RespMap {
+    const PREFIX: &'static str = "%";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let total_len = calc_total_length(buf, end, len, Self::PREFIX)?;
+        if buf.len() < total_len {
+            return Err(RespError::NotComplete);
+        }
+
+        buf.advance(end + CRLF_LEN);
+
+        let mut map = RespMap::new();
+        for _ in 0..len {
+            let key = SimpleString::decode(buf)?;
+            let value = RespFrame::decode(buf)?.ok_or(RespError::NotComplete)?;
+            map.insert(key.0, value);
+        }
+
+        Ok(map)
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        calc_total_length(buf, end, len, Self::PREFIX)
+    }
+}
+
+impl RespDecode for RespSet {
+    const PREFIX: &'static str = "~";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let total_len = calc_total_length(buf, end, len, Self::PREFIX)?;
+        if buf.len() < total_len {
+            return Err(RespError::NotComplete);
+        }
+
+        buf.advance(end + CRLF_LEN);
+
+        let mut frames = Vec::with_capacity(len);
+        for _ in 0..len {
+            frames.push(
+                RespFrame::decode(buf)?.ok_or(RespError::NotComplete)?,
+            );
+        }
+
+        Ok(RespSet::new(frames))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        calc_total_length(buf, end, len, Self::PREFIX)
+    }
+}
+
+impl RespDecode for BigNumber {
+    const PREFIX: &'static str = "(";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = std::str::from_utf8(&data[Self::PREFIX.len()..end])?;
+        Ok(BigNumber::new(normalize_big_number(s)?))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = find_crlf(buf, 1).ok_or(RespError::NotComplete)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for BulkError {
+    const PREFIX: &'static str = "!";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let remained = &buf[end + CRLF_LEN..];
+        if remained.len() < len + CRLF_LEN {
+            return Err(RespError::NotComplete);
+        }
+
+        buf.advance(end + CRLF_LEN);
+        let data = buf.split_to(len + CRLF_LEN);
+        Ok(BulkError::new(data[..len].to_vec()))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN + len + CRLF_LEN)
+    }
+}
+
+impl RespDecode for VerbatimString {
+    const PREFIX: &'static str = "=";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let remained = &buf[end + CRLF_LEN..];
+        if remained.len() < len + CRLF_LEN {
+            return Err(RespError::NotComplete);
+        }
+
+        buf.advance(end + CRLF_LEN);
+        let data = buf.split_to(len + CRLF_LEN);
+        let payload = &data[..len];
+        if payload.len() < 4 || payload[3] != b':' {
+            return Err(RespError::InvalidFrame(
+                "VerbatimString missing encoding tag".to_string(),
+            ));
+        }
+        let format = std::str::from_utf8(&payload[..3])?.to_string();
+        let data = payload[4..].to_vec();
+        Ok(VerbatimString::new(format, data))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN + len + CRLF_LEN)
+    }
+}
+
+impl RespDecode for RespPush {
+    const PREFIX: &'static str = ">";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let total_len = calc_total_length(buf, end, len, Self::PREFIX)?;
+        if buf.len() < total_len {
+            return Err(RespError::NotComplete);
+        }
+
+        buf.advance(end + CRLF_LEN);
+
+        let mut frames = Vec::with_capacity(len);
+        for _ in 0..len {
+            frames.push(RespFrame::decode(buf)?.ok_or(RespError::NotComplete)?);
+        }
+
+        Ok(RespPush::new(frames))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        calc_total_length(buf, end, len, Self::PREFIX)
+    }
+}
+
+/// Finds the index of the `nth` CRLF in `buf` (1-based), searching from index 1.
+fn find_crlf(buf: &[u8], nth: usize) -> Option<usize> {
+    if buf.len() < 2 {
+        return None;
+    }
+
+    let mut count = 0;
+    for i in 1..buf.len() - 1 {
+        if buf[i] == b'\r' && buf[i + 1] == b'\n' {
+            count += 1;
+            if count == nth {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+fn extract_fixed_data(
+    buf: &mut BytesMut,
+    expect: &str,
+    expect_type: &str,
+) -> Result<(), RespError> {
+    if buf.len() < expect.len() {
+        return Err(RespError::NotComplete);
+    }
+
+    if !buf.starts_with(expect.as_bytes()) {
+        return Err(RespError::InvalidFrameType(format!(
+            "expect: {}, got: {:?}",
+            expect_type, buf
+        )));
+    }
+
+    buf.advance(expect.len());
+    Ok(())
+}
+
+fn extract_simple_frame_data(buf: &[u8], prefix: &str) -> Result<usize, RespError> {
+    if buf.len() < 3 {
+        return Err(RespError::NotComplete);
+    }
+    if !buf.starts_with(prefix.as_bytes()) {
+        return Err(RespError::InvalidFrameType(format!(
+            "expect: {}, got: {:?}",
+            prefix, buf
+        )));
+    }
+
+    find_crlf(buf, 1).ok_or(RespError::NotComplete)
+}
+
+fn parse_length(buf: &[u8], prefix: &str) -> Result<(usize, usize), RespError> {
+    let end = extract_simple_frame_data(buf, prefix)?;
+    let s = std::str::from_utf8(&buf[prefix.len()..end])?;
+    Ok((end, s.parse()?))
+}
+
+/// Validates a `BigNumber` payload is a decimal integer and normalizes it: drops a `+`
+/// sign, strips leading zeros, and collapses `-0` to `0`.
+fn normalize_big_number(s: &str) -> Result<String, RespError> {
+    let (sign, digits) = match s.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(RespError::InvalidFrame(format!(
+            "Invalid big number: {}",
+            s
+        )));
+    }
+
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok("0".to_string());
+    }
+
+    Ok(format!("{}{}", sign, digits))
+}
+
+fn calc_total_length(
+    buf: &[u8],
+    end: usize,
+    len: usize,
+    prefix: &str,
+) -> Result<usize, RespError> {
+    let mut total = end + CRLF_LEN;
+    let mut data = &buf[total..];
+    match prefix {
+        "*" | "~" | ">" => {
+            for _ in 0..len {
+                let this_len = RespFrame::expect_length(data)?;
+                data = &data[this_len..];
+                total += this_len;
+            }
+            Ok(total)
+        }
+        "%" => {
+            for _ in 0..len {
+                let this_len = SimpleString::expect_length(data)?;
+                data = &data[this_len..];
+                total += this_len;
+
+                let this_len = RespFrame::expect_length(data)?;
+                data = &data[this_len..];
+                total += this_len;
+            }
+            Ok(total)
+        }
+        _ => Ok(len + CRLF_LEN),
+    }
+}
+
+impl RespFrame {
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        if buf.is_empty() {
+            return Err(RespError::NotComplete);
+        }
+
+        match buf[0] {
+            b'+' => SimpleString::expect_length(buf),
+            b'-' => SimpleError::expect_length(buf),
+            b':' => i64::expect_length(buf),
+            b'$' if buf.starts_with(b"$-1\r\n") => RespNullBulkString::expect_length(buf),
+            b'$' => BulkString::expect_length(buf),
+            b'*' if buf.starts_with(b"*-1\r\n") => RespNullArray::expect_length(buf),
+            b'*' => RespArray::expect_length(buf),
+            b'_' => RespNull::expect_length(buf),
+            b'#' => bool::expect_length(buf),
+            b',' => f64::expect_length(buf),
+            b'%' => RespMap::expect_length(buf),
+            b'~' => RespSet::expect_length(buf),
+            b'(' => BigNumber::expect_length(buf),
+            b'!' => BulkError::expect_length(buf),
+            b'=' => VerbatimString::expect_length(buf),
+            b'>' => RespPush::expect_length(buf),
+            _ => Err(RespError::InvalidFrameType(format!(
+                "Unknown frame type: {:?}",
+                buf
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use bytes::BufMut;
+
+    #[test]
+    fn test_simple_string_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"+OK\r\n");
+        let frame = SimpleString::decode(&mut buf)?;
+        assert_eq!(frame, SimpleString::new("OK"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_simple_string_decode_not_complete() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"+OK\r");
+        let ret = SimpleString::decode(&mut buf);
+        assert_eq!(ret.unwrap_err(), RespError::NotComplete);
+
+        buf.put_u8(b'\n');
+        let frame = SimpleString::decode(&mut buf)?;
+        assert_eq!(frame, SimpleString::new("OK"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_integer_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b":+123\r\n");
+        let frame = i64::decode(&mut buf)?;
+        assert_eq!(frame, 123);
+
+        buf.extend_from_slice(b":-123\r\n");
+        let frame = i64::decode(&mut buf)?;
+        assert_eq!(frame, -123);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_string_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"$6\r\nfoobar\r\n");
+        let frame = BulkString::decode(&mut buf)?;
+        assert_eq!(frame, BulkString::new(b"foobar".to_vec()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_bulk_string_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"$-1\r\n");
+        let frame = RespNullBulkString::decode(&mut buf)?;
+        assert_eq!(frame, RespNullBulkString);
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*2\r\n$3\r\nfoo\r\n$3\r\nbar\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            RespArray::new([b"foo".as_slice().into(), b"bar".as_slice().into()])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_decode_not_complete() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*2\r\n$3\r\nfoo\r\n");
+        let ret = RespArray::decode(&mut buf);
+        assert_eq!(ret.unwrap_err(), RespError::NotComplete);
+
+        buf.extend_from_slice(b"$3\r\nbar\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            RespArray::new([b"foo".as_slice().into(), b"bar".as_slice().into()])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_array_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*-1\r\n");
+        let frame = RespNullArray::decode(&mut buf)?;
+        assert_eq!(frame, RespNullArray);
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"_\r\n");
+        let frame = RespNull::decode(&mut buf)?;
+        assert_eq!(frame, RespNull);
+        Ok(())
+    }
+
+    #[test]
+    fn test_boolean_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"#t\r\n");
+        let frame = bool::decode(&mut buf)?;
+        assert!(frame);
+
+        buf.extend_from_slice(b"#f\r\n");
+        let frame = bool::decode(&mut buf)?;
+        assert!(!frame);
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b",+1.23\r\n");
+        let frame = f64::decode(&mut buf)?;
+        assert_eq!(frame, 1.23);
+
+        buf.extend_from_slice(b",-1.23\r\n");
+        let frame = f64::decode(&mut buf)?;
+        assert_eq!(frame, -1.23);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"%1\r\n+key\r\n$5\r\nvalue\r\n");
+        let frame = RespMap::decode(&mut buf)?;
+        let mut map = RespMap::new();
+        map.insert("key".to_string(), BulkString::new(b"value".to_vec()).into());
+        assert_eq!(frame, map);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_decode_over_declared_does_not_panic() {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"%2\r\n+k\r\n:1\r\n");
+        let ret = RespMap::decode(&mut buf);
+        assert_eq!(ret.unwrap_err(), RespError::NotComplete);
+    }
+
+    #[test]
+    fn test_array_decode_with_null_bulk_string() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*2\r\n$-1\r\n$3\r\nfoo\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            RespArray::new([RespNullBulkString.into(), b"foo".as_slice().into()])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_decode_with_null_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*2\r\n*-1\r\n$3\r\nfoo\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            RespArray::new([RespNullArray.into(), b"foo".as_slice().into()])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"~2\r\n:+1\r\n$3\r\nfoo\r\n");
+        let frame = RespSet::decode(&mut buf)?;
+        assert_eq!(frame, RespSet::new([1.into(), b"foo".as_slice().into()]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_number_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"(3492890328409238509324850943850943825024385\r\n");
+        let frame = BigNumber::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            BigNumber::new("3492890328409238509324850943850943825024385")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_number_decode_normalizes_leading_zeros() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"(-0042\r\n");
+        let frame = BigNumber::decode(&mut buf)?;
+        assert_eq!(frame, BigNumber::new("-42"));
+
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"(-0\r\n");
+        let frame = BigNumber::decode(&mut buf)?;
+        assert_eq!(frame, BigNumber::new("0"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_number_decode_rejects_non_decimal() {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"(abc\r\n");
+        let ret = BigNumber::decode(&mut buf);
+        assert!(matches!(ret, Err(RespError::InvalidFrame(_))));
+    }
+
+    #[test]
+    fn test_bulk_error_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"!21\r\nSYNTAX invalid syntax\r\n");
+        let frame = BulkError::decode(&mut buf)?;
+        assert_eq!(frame, BulkError::new(b"SYNTAX invalid syntax".to_vec()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verbatim_string_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"=15\r\ntxt:Some string\r\n");
+        let frame = VerbatimString::decode(&mut buf)?;
+        assert_eq!(frame, VerbatimString::new("txt", b"Some string".to_vec()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b">2\r\n+orange\r\n+apple\r\n");
+        let frame = RespPush::decode(&mut buf)?;
+        assert_eq!(
+            frame,
+            RespPush::new([
+                SimpleString::new("orange").into(),
+                SimpleString::new("apple").into(),
+            ])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{
+    BigNumber, BulkError, BulkString, RespArray, RespFrame, RespMap, RespNull, RespNullArray,
+    RespNullBulkString, RespPush, RespSet, RespVersion, SimpleError, SimpleString, VerbatimString,
+};
+
+use super::RespEncode;
+
+const BUF_CAP: usize = 4096;
+
+impl RespFrame {
+    /// Like `encode`, but RESP2/RESP3-aware for the handful of types that differ between
+    /// dialects (null, boolean, map) so a HELLO-negotiated connection gets the wire format
+    /// it asked for. Only the frame itself is converted: elements nested inside an array,
+    /// map, set or push are encoded as by `encode`.
+    pub fn encode_with(self, version: RespVersion) -> Vec<u8> {
+        match (self, version) {
+            (RespFrame::Null(_), RespVersion::Resp2) => RespNullBulkString.encode(),
+            (RespFrame::NullBulkString(_), RespVersion::Resp3) => RespNull.encode(),
+            (RespFrame::NullArray(_), RespVersion::Resp3) => RespNull.encode(),
+            (RespFrame::Boolean(b), RespVersion::Resp2) => (if b { 1i64 } else { 0i64 }).encode(),
+            (RespFrame::Map(map), RespVersion::Resp2) => {
+                let pairs: Vec<RespFrame> = map
+                    .0
+                    .into_iter()
+                    .flat_map(|(k, v)| [SimpleString::new(k).into(), v])
+                    .collect();
+                RespArray::new(pairs).encode()
+            }
+            (frame, _) => frame.encode(),
+        }
+    }
+}
+
+impl RespEncode for SimpleString {
+    fn encode(self) -> Vec<u8> {
+        format!("+{}\r\n", self.0).into_bytes()
+    }
+}
+
+impl RespEncode for SimpleError {
+    fn encode(self) -> Vec<u8> {
+        format!("-{}\r\n", self.0).into_bytes()
+    }
+}
+
+impl RespEncode for i64 {
+    fn encode(self) -> Vec<u8> {
+        let sign = if self < 0 { "" } else { "+" };
+        format!(":{}{}\r\n", sign, self).into_bytes()
+    }
+}
+
+impl RespEncode for BulkString {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.len() + 16);
+        buf.extend_from_slice(format!("${}\r\n", self.len()).as_bytes());
+        buf.extend_from_slice(&self.0);
+        buf.extend_from_slice(b"\r\n");
+        buf
+    }
+}
+
+impl RespEncode for RespNullBulkString {
+    fn encode(self) -> Vec<u8> {
+        b"$-1\r\n".to_vec()
+    }
+}
+
+impl RespEncode for RespArray {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(BUF_CAP);
+        buf.extend_from_slice(format!("*{}\r\n", self.len()).as_bytes());
+        for frame in self.0 {
+            buf.extend_from_slice(&frame.encode());
+        }
+        buf
+    }
+}
+
+impl RespEncode for RespNullArray {
+    fn encode(self) -> Vec<u8> {
+        b"*-1\r\n".to_vec()
+    }
+}
+
+impl RespEncode for RespNull {
+    fn encode(self) -> Vec<u8> {
+        b"_\r\n".to_vec()
+    }
+}
+
+impl RespEncode for bool {
+    fn encode(self) -> Vec<u8> {
+        format!("#{}\r\n", if self { "t" } else { "f" }).into_bytes()
+    }
+}
+
+impl RespEncode for f64 {
+    fn encode(self) -> Vec<u8> {
+        let ret = if self.abs() > 1e+8 || (self.abs() < 1e-8 && self != 0.0) {
+            format!("{:+e}", self)
+        } else {
+            let sign = if self < 0.0 { "" } else { "+" };
+            format!("{}{}", sign, self)
+        };
+        format!(",{}\r\n", ret).into_bytes()
+    }
+}
+
+impl RespEncode for RespMap {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(BUF_CAP);
+        buf.extend_from_slice(format!("%{}\r\n", self.len()).as_bytes());
+        for (key, value) in self.0 {
+            buf.extend_from_slice(&SimpleString::new(key).encode());
+            buf.extend_from_slice(&value.encode());
+        }
+        buf
+    }
+}
+
+impl RespEncode for RespSet {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(BUF_CAP);
+        buf.extend_from_slice(format!("~{}\r\n", self.len()).as_bytes());
+        for frame in self.0 {
+            buf.extend_from_slice(&frame.encode());
+        }
+        buf
+    }
+}
+
+impl RespEncode for BigNumber {
+    fn encode(self) -> Vec<u8> {
+        format!("({}\r\n", self.0).into_bytes()
+    }
+}
+
+impl RespEncode for BulkError {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.len() + 16);
+        buf.extend_from_slice(format!("!{}\r\n", self.len()).as_bytes());
+        buf.extend_from_slice(&self.0);
+        buf.extend_from_slice(b"\r\n");
+        buf
+    }
+}
+
+impl RespEncode for VerbatimString {
+    fn encode(self) -> Vec<u8> {
+        let len = self.format.len() + 1 + self.data.len();
+        let mut buf = Vec::with_capacity(len + 16);
+        buf.extend_from_slice(format!("={}\r\n", len).as_bytes());
+        buf.extend_from_slice(self.format.as_bytes());
+        buf.push(b':');
+        buf.extend_from_slice(&self.data);
+        buf.extend_from_slice(b"\r\n");
+        buf
+    }
+}
+
+impl RespEncode for RespPush {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(BUF_CAP);
+        buf.extend_from_slice(format!(">{}\r\n", self.len()).as_bytes());
+        for frame in self.0 {
+            buf.extend_from_slice(&frame.encode());
+        }
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RespFrame;
+
+    #[test]
+    fn test_simple_string_encode() {
+        let frame: RespFrame = SimpleString::new("OK").into();
+        assert_eq!(frame.encode(), b"+OK\r\n");
+    }
+
+    #[test]
+    fn test_simple_error_encode() {
+        let frame: RespFrame = SimpleError::new("Error message").into();
+        assert_eq!(frame.encode(), b"-Error message\r\n");
+    }
+
+    #[test]
+    fn test_integer_encode() {
+        let frame: RespFrame = 123.into();
+        assert_eq!(frame.encode(), b":+123\r\n");
+
+        let frame: RespFrame = (-123).into();
+        assert_eq!(frame.encode(), b":-123\r\n");
+    }
+
+    #[test]
+    fn test_bulk_string_encode() {
+        let frame: RespFrame = BulkString::new(b"foobar".to_vec()).into();
+        assert_eq!(frame.encode(), b"$6\r\nfoobar\r\n");
+    }
+
+    #[test]
+    fn test_null_bulk_string_encode() {
+        let frame: RespFrame = RespNullBulkString.into();
+        assert_eq!(frame.encode(), b"$-1\r\n");
+    }
+
+    #[test]
+    fn test_array_encode() {
+        let frame: RespFrame = RespArray::new(vec![
+            BulkString::new(b"foo".to_vec()).into(),
+            BulkString::new(b"bar".to_vec()).into(),
+        ])
+        .into();
+        assert_eq!(frame.encode(), b"*2\r\n$3\r\nfoo\r\n$3\r\nbar\r\n");
+    }
+
+    #[test]
+    fn test_null_array_encode() {
+        let frame: RespFrame = RespNullArray.into();
+        assert_eq!(frame.encode(), b"*-1\r\n");
+    }
+
+    #[test]
+    fn test_null_encode() {
+        let frame: RespFrame = RespNull.into();
+        assert_eq!(frame.encode(), b"_\r\n");
+    }
+
+    #[test]
+    fn test_boolean_encode() {
+        let frame: RespFrame = true.into();
+        assert_eq!(frame.encode(), b"#t\r\n");
+
+        let frame: RespFrame = false.into();
+        assert_eq!(frame.encode(), b"#f\r\n");
+    }
+
+    #[test]
+    fn test_double_encode() {
+        let frame: RespFrame = 1.23.into();
+        assert_eq!(frame.encode(), b",+1.23\r\n");
+
+        let frame: RespFrame = (-1.23).into();
+        assert_eq!(frame.encode(), b",-1.23\r\n");
+    }
+
+    #[test]
+    fn test_map_encode() {
+        let mut map = RespMap::new();
+        map.insert("key".to_string(), BulkString::new(b"value".to_vec()).into());
+        let frame: RespFrame = map.into();
+        assert_eq!(frame.encode(), b"%1\r\n+key\r\n$5\r\nvalue\r\n");
+    }
+
+    #[test]
+    fn test_set_encode() {
+        let frame: RespFrame =
+            RespSet::new(vec![1.into(), BulkString::new(b"foo".to_vec()).into()]).into();
+        assert_eq!(frame.encode(), b"~2\r\n:+1\r\n$3\r\nfoo\r\n");
+    }
+
+    #[test]
+    fn test_big_number_encode() {
+        let frame: RespFrame =
+            BigNumber::new("3492890328409238509324850943850943825024385").into();
+        assert_eq!(
+            frame.encode(),
+            b"(3492890328409238509324850943850943825024385\r\n"
+        );
+    }
+
+    #[test]
+    fn test_bulk_error_encode() {
+        let frame: RespFrame = BulkError::new(b"SYNTAX invalid syntax".to_vec()).into();
+        assert_eq!(frame.encode(), b"!21\r\nSYNTAX invalid syntax\r\n");
+    }
+
+    #[test]
+    fn test_verbatim_string_encode() {
+        let frame: RespFrame = VerbatimString::new("txt", b"Some string".to_vec()).into();
+        assert_eq!(frame.encode(), b"=15\r\ntxt:Some string\r\n");
+    }
+
+    #[test]
+    fn test_push_encode() {
+        let frame: RespFrame = RespPush::new(vec![
+            SimpleString::new("orange").into(),
+            SimpleString::new("apple").into(),
+        ])
+        .into();
+        assert_eq!(frame.encode(), b">2\r\n+orange\r\n+apple\r\n");
+    }
+
+    #[test]
+    fn test_null_encode_with_version() {
+        let frame: RespFrame = RespNull.into();
+        assert_eq!(frame.clone().encode_with(RespVersion::Resp3), b"_\r\n");
+        assert_eq!(frame.encode_with(RespVersion::Resp2), b"$-1\r\n");
+    }
+
+    #[test]
+    fn test_boolean_encode_with_version() {
+        let frame: RespFrame = true.into();
+        assert_eq!(frame.clone().encode_with(RespVersion::Resp3), b"#t\r\n");
+        assert_eq!(frame.encode_with(RespVersion::Resp2), b":+1\r\n");
+    }
+
+    #[test]
+    fn test_map_encode_with_version() {
+        let mut map = RespMap::new();
+        map.insert("key".to_string(), BulkString::new(b"value".to_vec()).into());
+        let frame: RespFrame = map.into();
+        assert_eq!(
+            frame.clone().encode_with(RespVersion::Resp3),
+            b"%1\r\n+key\r\n$5\r\nvalue\r\n"
+        );
+        assert_eq!(
+            frame.encode_with(RespVersion::Resp2),
+            b"*2\r\n+key\r\n$5\r\nvalue\r\n"
+        );
+    }
+}
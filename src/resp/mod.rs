@@ -108,6 +108,24 @@ pub enum RespError {
     Utf8Error(#[from] std::str::Utf8Error),
     #[error("Parse float error: {0}")]
     ParseFloatError(#[from] std::num::ParseFloatError),
+    #[error("IO error: {0}")]
+    IoError(String),
+}
+
+impl From<std::io::Error> for RespError {
+    fn from(e: std::io::Error) -> Self {
+        RespError::IoError(e.to_string())
+    }
+}
+
+/// The RESP dialect a connection has negotiated via `HELLO`. Some types (null, boolean,
+/// map) serialize differently depending on this, so it's threaded through
+/// `RespFrame::encode_with` rather than baked into `RespEncode::encode`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RespVersion {
+    #[default]
+    Resp2,
+    Resp3,
 }
 
 #[enum_dispatch(RespEncode)]
@@ -126,6 +144,11 @@ pub enum RespFrame {
     Double(f64),
     Map(RespMap),
     Set(RespSet),
+
+    BigNumber(BigNumber),
+    BulkError(BulkError),
+    VerbatimString(VerbatimString),
+    Push(RespPush),
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -155,6 +178,27 @@ pub struct RespMap(pub(crate) BTreeMap<String, RespFrame>);
 #[derive(Debug, PartialEq, Clone)]
 pub struct RespSet(pub(crate) Vec<RespFrame>);
 
+/// Arbitrary-precision integer, stored as a decimal string since it may overflow any
+/// fixed-width integer type. Decoding validates the payload is `[+-]?[0-9]+` and
+/// normalizes it (no sign on positive numbers, no leading zeros, `-0` collapses to `0`);
+/// `BigNumber::new` itself does not re-validate already-trusted values.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BigNumber(pub(crate) String);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BulkError(pub(crate) Vec<u8>);
+
+/// A string tagged with its 3-byte encoding (e.g. `txt`, `mkd`), with the tag and the
+/// payload kept separate so callers don't have to re-split on `:`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct VerbatimString {
+    pub(crate) format: String,
+    pub(crate) data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RespPush(pub(crate) Vec<RespFrame>);
+
 impl Deref for SimpleString {
     type Target = String;
 
@@ -209,6 +253,30 @@ impl DerefMut for RespMap {
     }
 }
 
+impl Deref for BigNumber {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Deref for BulkError {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Deref for RespPush {
+    type Target = Vec<RespFrame>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 impl SimpleString {
     pub fn new(s: impl Into<String>) -> Self {
         Self(s.into())
@@ -251,6 +319,33 @@ impl RespSet {
     }
 }
 
+impl BigNumber {
+    pub fn new(s: impl Into<String>) -> Self {
+        BigNumber(s.into())
+    }
+}
+
+impl BulkError {
+    pub fn new(s: impl Into<Vec<u8>>) -> Self {
+        BulkError(s.into())
+    }
+}
+
+impl VerbatimString {
+    pub fn new(format: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        VerbatimString {
+            format: format.into(),
+            data: data.into(),
+        }
+    }
+}
+
+impl RespPush {
+    pub fn new(s: impl Into<Vec<RespFrame>>) -> Self {
+        RespPush(s.into())
+    }
+}
+
 impl From<&str> for SimpleString {
     fn from(s: &str) -> Self {
         SimpleString::new(s)